@@ -17,7 +17,298 @@ use neutralts::utils;
 use neutralts::Template;
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict, PyList, PyTuple};
+use regex::Regex;
 use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+/// A parsed template cached alongside the file's last-known modification time.
+struct CachedTemplate {
+    /// The parsed template, kept free of any schema so it can be cloned and
+    /// reused across renders with different data.
+    template: Template,
+    /// Modification time of the source file at the time it was parsed.
+    mtime: SystemTime,
+}
+
+/// Process-wide, bounded LRU cache of parsed templates keyed by absolute file path.
+///
+/// Mirrors Jinja2's `Environment` template cache: repeated renders of the same
+/// file reuse the parsed template instead of re-reading and re-parsing it.
+struct TemplateCache {
+    /// Maximum number of entries to retain before evicting the least recently used.
+    capacity: usize,
+    /// Cached entries by absolute file path.
+    entries: HashMap<PathBuf, CachedTemplate>,
+    /// Access order, least recently used first.
+    order: Vec<PathBuf>,
+}
+
+impl TemplateCache {
+    fn new(capacity: usize) -> Self {
+        TemplateCache {
+            capacity,
+            entries: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Moves `path` to the most-recently-used end of the access order.
+    fn touch(&mut self, path: &PathBuf) {
+        if let Some(pos) = self.order.iter().position(|p| p == path) {
+            let p = self.order.remove(pos);
+            self.order.push(p);
+        }
+    }
+
+    /// Inserts or refreshes a parsed template, evicting the least recently used
+    /// entry first if the cache is at capacity.
+    fn insert(&mut self, path: PathBuf, template: Template, mtime: SystemTime) {
+        if self.entries.contains_key(&path) {
+            self.touch(&path);
+        } else {
+            if self.capacity > 0 && self.order.len() >= self.capacity {
+                let oldest = self.order.remove(0);
+                self.entries.remove(&oldest);
+            }
+            self.order.push(path.clone());
+        }
+        self.entries.insert(path, CachedTemplate { template, mtime });
+    }
+}
+
+/// Returns the process-wide template cache, initialized on first use.
+///
+/// The cache's capacity is fixed at process-wide first use by whichever
+/// `NeutralTemplate` instance asks for the cache first; later callers with a
+/// different `cache_size` do not reshape the shared cache out from under
+/// each other.
+fn template_cache(initial_capacity: usize) -> &'static Mutex<TemplateCache> {
+    static CACHE: OnceLock<Mutex<TemplateCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(TemplateCache::new(initial_capacity)))
+}
+
+/// Warns the caller, via Python's `warnings` module, that `search_paths`
+/// resolves only the main template file and not template includes.
+///
+/// This is a real gap against the "theme/override layout" use case
+/// `search_paths` is meant for, since those typically shadow included
+/// headers/footers/partials rather than the entry-point file. Surfacing it at
+/// runtime (rather than only in the docstring) means every caller who opts
+/// into `search_paths` is actually told about the limitation.
+fn warn_search_paths_include_limitation(py: Python<'_>) -> PyResult<()> {
+    py.import("warnings")?.call_method1(
+        "warn",
+        (
+            "NeutralTemplate search_paths only resolves the main template file; \
+             template includes are still resolved via the underlying engine's \
+             own default path logic and are not searched across search_paths.",
+        ),
+    )?;
+    Ok(())
+}
+
+/// Returns the JSON Schema type name of a `serde_json::Value`.
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Checks `instance` against a schema `type` keyword, which may be a single
+/// type name or an array of allowed type names.
+fn type_matches(instance: &Value, expected: &Value) -> bool {
+    let matches_one = |name: &str| match name {
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        "integer" => instance.as_f64().map_or(false, |f| f.fract() == 0.0),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "null" => instance.is_null(),
+        _ => true,
+    };
+    match expected {
+        Value::String(name) => matches_one(name),
+        Value::Array(names) => names.iter().filter_map(Value::as_str).any(matches_one),
+        _ => true,
+    }
+}
+
+/// Loads a schema file (YAML or JSON, sniffed transparently) and, if
+/// `resolve_refs` is set, inlines any `$ref` keys found in it.
+///
+/// `stack` tracks, in resolution order, the canonicalized paths currently
+/// being resolved so that a cyclic chain of `$ref`s is reported instead of
+/// recursing forever.
+fn load_schema_file(path: &Path, resolve_refs: bool, stack: &mut Vec<PathBuf>) -> Result<Value, String> {
+    let canonical = std::fs::canonicalize(path)
+        .map_err(|e| format!("cannot resolve schema file '{}': {}", path.display(), e))?;
+
+    if let Some(pos) = stack.iter().position(|p| p == &canonical) {
+        let chain: Vec<String> = stack[pos..].iter().map(|p| p.display().to_string()).collect();
+        return Err(format!(
+            "cyclic $ref detected: {} -> {}",
+            chain.join(" -> "),
+            canonical.display()
+        ));
+    }
+    stack.push(canonical.clone());
+
+    let contents = std::fs::read_to_string(&canonical)
+        .map_err(|e| format!("cannot read schema file '{}': {}", canonical.display(), e))?;
+    let mut value: Value = serde_yaml::from_str(&contents)
+        .map_err(|e| format!("schema file '{}' is not valid JSON/YAML: {}", canonical.display(), e))?;
+
+    if resolve_refs {
+        let base_dir = canonical.parent().map(Path::to_path_buf).unwrap_or_default();
+        resolve_refs_in(&mut value, &base_dir, stack)?;
+    }
+
+    stack.pop();
+    Ok(value)
+}
+
+/// Recursively inlines `$ref` keys found anywhere in `value`.
+///
+/// An object containing `"$ref": "path/to/file.yaml"` is replaced by the
+/// parsed contents of that file (resolved relative to `base_dir`), with any
+/// other keys on the same object merged back over the result so sibling keys
+/// win over the referenced file.
+fn resolve_refs_in(value: &mut Value, base_dir: &Path, stack: &mut Vec<PathBuf>) -> Result<(), String> {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(ref_path)) = map.get("$ref").cloned() {
+                map.remove("$ref");
+                let mut resolved = load_schema_file(&base_dir.join(&ref_path), true, stack)?;
+                let mut siblings = Value::Object(map.clone());
+                resolve_refs_in(&mut siblings, base_dir, stack)?;
+                utils::merge_schema(&mut resolved, &siblings);
+                *value = resolved;
+            } else {
+                for child in map.values_mut() {
+                    resolve_refs_in(child, base_dir, stack)?;
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                resolve_refs_in(item, base_dir, stack)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Validates `instance` against `schema` (a Draft-7 JSON Schema subset),
+/// appending a `(path, message)` entry for every violation found.
+///
+/// Supports `type`, `required`, `properties`, `additionalProperties`, `items`,
+/// `enum`, `minimum`/`maximum`, `minLength`/`maxLength`, and `pattern`.
+fn validate_schema(instance: &Value, schema: &Value, path: &str, errors: &mut Vec<(String, String)>) {
+    let schema = match schema.as_object() {
+        Some(obj) => obj,
+        None => return,
+    };
+
+    if let Some(expected) = schema.get("type") {
+        if !type_matches(instance, expected) {
+            errors.push((
+                path.to_string(),
+                format!("expected type {}, got {}", expected, json_type_name(instance)),
+            ));
+            return;
+        }
+    }
+
+    if let Some(enum_values) = schema.get("enum").and_then(Value::as_array) {
+        if !enum_values.contains(instance) {
+            errors.push((path.to_string(), "value is not one of the allowed enum values".to_string()));
+        }
+    }
+
+    match instance {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        errors.push((format!("{}/{}", path, key), "required property is missing".to_string()));
+                    }
+                }
+            }
+
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(properties) = properties {
+                for (key, prop_schema) in properties {
+                    if let Some(value) = map.get(key) {
+                        validate_schema(value, prop_schema, &format!("{}/{}", path, key), errors);
+                    }
+                }
+            }
+
+            if let Some(Value::Bool(false)) = schema.get("additionalProperties") {
+                let known = properties;
+                for key in map.keys() {
+                    if known.map_or(true, |p| !p.contains_key(key)) {
+                        errors.push((format!("{}/{}", path, key), "additional property is not allowed".to_string()));
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_schema(item, item_schema, &format!("{}/{}", path, index), errors);
+                }
+            }
+        }
+        Value::Number(_) => {
+            let value = instance.as_f64().unwrap_or(f64::NAN);
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if value < min {
+                    errors.push((path.to_string(), format!("value is less than minimum {}", min)));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if value > max {
+                    errors.push((path.to_string(), format!("value is greater than maximum {}", max)));
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min_len) = schema.get("minLength").and_then(Value::as_u64) {
+                if len < min_len {
+                    errors.push((path.to_string(), format!("string is shorter than minLength {}", min_len)));
+                }
+            }
+            if let Some(max_len) = schema.get("maxLength").and_then(Value::as_u64) {
+                if len > max_len {
+                    errors.push((path.to_string(), format!("string is longer than maxLength {}", max_len)));
+                }
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                match Regex::new(pattern) {
+                    Ok(re) if !re.is_match(s) => {
+                        errors.push((path.to_string(), format!("string does not match pattern '{}'", pattern)));
+                    }
+                    Ok(_) => {}
+                    Err(e) => errors.push((path.to_string(), format!("invalid pattern '{}': {}", pattern, e))),
+                }
+            }
+        }
+        _ => {}
+    }
+}
 
 /// Internal representation of template source type.
 enum TplType {
@@ -81,6 +372,16 @@ struct NeutralTemplate {
     status_param: String,
     /// Whether an error occurred during last render.
     has_error: bool,
+    /// Whether to use the process-wide compiled-template cache.
+    cache: bool,
+    /// Maximum number of entries the process-wide cache should retain.
+    cache_size: usize,
+    /// Whether to re-parse a cached template when the file's mtime changes.
+    auto_reload: bool,
+    /// JSON Schema to validate against when `validate()` is called without arguments.
+    validate_with: Option<Value>,
+    /// Directories searched, in order, to resolve the main template file.
+    search_paths: Vec<String>,
 }
 
 impl NeutralTemplate {
@@ -171,6 +472,123 @@ impl NeutralTemplate {
         ))
     }
 
+    /// Loads a template through the process-wide compiled-template cache.
+    ///
+    /// The cached entry is parsed with an empty schema so it can be shared and
+    /// cloned independently of whatever schema the caller merges in afterwards.
+    /// When `auto_reload` is set, the file's modification time is compared
+    /// against the cached entry and the template is re-parsed if it changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the template file.
+    /// * `auto_reload` - Whether to re-parse the template if the file changed on disk.
+    /// * `cache_size` - Capacity to initialize the process-wide cache with, the
+    ///   first time any instance populates it. Later calls with a different
+    ///   `cache_size` do not resize the already-initialized shared cache. Must
+    ///   be greater than zero; callers treat `0` as "caching disabled" and
+    ///   skip this function entirely.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the path cannot be resolved, its metadata
+    /// cannot be read, or the template fails to parse.
+    fn get_cached_template(
+        path: &str,
+        auto_reload: bool,
+        cache_size: usize,
+    ) -> Result<Template, String> {
+        let abs_path = std::fs::canonicalize(path)
+            .map_err(|e| format!("cannot resolve template path '{}': {}", path, e))?;
+        let mtime = std::fs::metadata(&abs_path)
+            .and_then(|m| m.modified())
+            .map_err(|e| format!("cannot read metadata for '{}': {}", path, e))?;
+
+        let cache = template_cache(cache_size);
+        {
+            let mut guard = cache.lock().unwrap();
+            if let Some(cached) = guard.entries.get(&abs_path) {
+                if !auto_reload || cached.mtime == mtime {
+                    guard.touch(&abs_path);
+                    return Ok(guard.entries.get(&abs_path).unwrap().template.clone());
+                }
+            }
+        }
+
+        // Parse outside the lock so an unrelated file's cache hit (or insert)
+        // is never blocked behind this one's parse.
+        let template = Template::from_file_value(path, serde_json::json!({}))
+            .map_err(|e| format!("Template::from_file_value() failed: {}", e))?;
+
+        let mut guard = cache.lock().unwrap();
+        guard.insert(abs_path, template.clone(), mtime);
+        Ok(template)
+    }
+
+    /// Resolves `name` against a list of search directories, in order, and
+    /// returns the first path that exists on disk.
+    ///
+    /// Modeled on Jinja2's `FileSystemLoader`: this lets a project directory
+    /// shadow a shared base-template directory for the main template file.
+    /// This only resolves the main template passed to `NeutralTemplate`/
+    /// `set_path()` — template includes still resolve through the underlying
+    /// `neutralts::Template`'s own path logic and are not searched across
+    /// `search_paths`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message listing every directory searched if `name`
+    /// is not found in any of them.
+    fn resolve_in_search_paths(name: &str, search_paths: &[String]) -> Result<String, String> {
+        for dir in search_paths {
+            let candidate = Path::new(dir).join(name);
+            if candidate.is_file() {
+                return Ok(candidate.to_string_lossy().into_owned());
+            }
+        }
+        Err(format!(
+            "template '{}' not found in any search path: [{}]",
+            name,
+            search_paths.join(", ")
+        ))
+    }
+
+    /// Builds a snapshot of the current merged schema data as a `serde_json::Value`.
+    ///
+    /// Applies the base schema followed by every merged schema in order, the
+    /// same sequence `run_render` uses, but without touching the template engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if any component is a MessagePack schema, which
+    /// cannot be introspected without decoding support in this crate.
+    fn merged_schema_snapshot(&self) -> Result<Value, String> {
+        let mut merged = match &self.base_schema {
+            BaseSchema::None => serde_json::json!({}),
+            BaseSchema::Json(schema) => schema.clone(),
+            BaseSchema::Msgpack(_) => {
+                return Err(
+                    "validate() cannot introspect a MessagePack schema; use schema_str/schema_obj instead"
+                        .to_string(),
+                )
+            }
+        };
+
+        for merge in &self.schema_merges {
+            match merge {
+                SchemaMerge::Json(schema) => utils::merge_schema(&mut merged, schema),
+                SchemaMerge::Msgpack(_) => {
+                    return Err(
+                        "validate() cannot introspect a MessagePack schema; use schema_str/schema_obj instead"
+                            .to_string(),
+                    )
+                }
+            }
+        }
+
+        Ok(merged)
+    }
+
     /// Executes the template rendering process.
     ///
     /// This internal method handles the actual rendering logic, including:
@@ -196,14 +614,51 @@ impl NeutralTemplate {
         let (contents, status_code, status_text, status_param, has_error) = py
             .detach(|| {
                 let mut template = match &self.tpl {
-                    TplType::FilePath(path) => match &self.base_schema {
-                        BaseSchema::None => Template::from_file_value(path, serde_json::json!({}))
-                            .map_err(|e| format!("Template::from_file_value() failed: {}", e))?,
-                        BaseSchema::Json(schema) => Template::from_file_value(path, schema.clone())
-                            .map_err(|e| format!("Template::from_file_value() failed: {}", e))?,
-                        BaseSchema::Msgpack(bytes) => Template::from_file_msgpack(path, bytes)
-                            .map_err(|e| format!("Template::from_file_msgpack() failed: {}", e))?,
-                    },
+                    TplType::FilePath(path) => {
+                        let resolved_path = if self.search_paths.is_empty() {
+                            path.clone()
+                        } else {
+                            Self::resolve_in_search_paths(path, &self.search_paths)?
+                        };
+                        let path = &resolved_path;
+                        if self.cache && self.cache_size > 0 {
+                            let mut template = Self::get_cached_template(
+                                path,
+                                self.auto_reload,
+                                self.cache_size,
+                            )?;
+                            match &self.base_schema {
+                                BaseSchema::None => {}
+                                BaseSchema::Json(schema) => {
+                                    template.merge_schema_value(schema.clone());
+                                }
+                                BaseSchema::Msgpack(bytes) => {
+                                    template.merge_schema_msgpack(bytes).map_err(|e| {
+                                        format!("merge_schema_msgpack failed: {}", e)
+                                    })?;
+                                }
+                            }
+                            template
+                        } else {
+                            match &self.base_schema {
+                                BaseSchema::None => {
+                                    Template::from_file_value(path, serde_json::json!({}))
+                                        .map_err(|e| {
+                                            format!("Template::from_file_value() failed: {}", e)
+                                        })?
+                                }
+                                BaseSchema::Json(schema) => {
+                                    Template::from_file_value(path, schema.clone()).map_err(|e| {
+                                        format!("Template::from_file_value() failed: {}", e)
+                                    })?
+                                }
+                                BaseSchema::Msgpack(bytes) => Template::from_file_msgpack(path, bytes)
+                                    .map_err(|e| {
+                                        format!("Template::from_file_msgpack() failed: {}", e)
+                                    })?,
+                            }
+                        }
+                    }
                     TplType::RawSource(source) => {
                         let mut template = Template::new()
                             .map_err(|e| format!("Template::new() failed: {}", e))?;
@@ -272,8 +727,27 @@ impl NeutralTemplate {
     /// * `schema_str` - Optional JSON schema as a string.
     /// * `schema_msgpack` - Optional MessagePack schema as bytes.
     /// * `schema_obj` - Optional Python dict/list as schema.
-    ///
-    /// Only one of `schema_str`, `schema_msgpack`, or `schema_obj` can be provided.
+    /// * `schema_yaml` - Optional schema as a YAML string.
+    /// * `schema_file` - Optional path to a YAML or JSON schema file, loaded
+    ///   the same way as `merge_schema_file()`.
+    /// * `resolve_refs` - Whether `$ref` keys in `schema_file` are resolved
+    ///   against sibling files. Defaults to `true`.
+    /// * `cache` - If `true`, parsed file templates are kept in a process-wide
+    ///   LRU cache keyed by absolute path, avoiding re-parsing on every render.
+    /// * `cache_size` - Maximum number of entries the process-wide cache should
+    ///   retain. Defaults to 128. `0` disables the cache entirely, regardless
+    ///   of `cache`. The shared cache's capacity is set by the first instance
+    ///   that uses it; a different `cache_size` on a later instance does not
+    ///   resize it.
+    /// * `auto_reload` - If `true`, a cached template is re-parsed when the
+    ///   file's modification time changes on disk.
+    /// * `validate_with` - Optional JSON Schema string used by `validate()`
+    ///   when called without a `schema_json` argument.
+    /// * `search_paths` - Optional list of directories searched, in order, to
+    ///   resolve the main template file, the same as `set_search_paths()`.
+    ///
+    /// Only one of `schema_str`, `schema_msgpack`, `schema_obj`, `schema_yaml`,
+    /// or `schema_file` can be provided.
     ///
     /// # Returns
     ///
@@ -296,26 +770,40 @@ impl NeutralTemplate {
     /// # From file with Python dict schema
     /// template = NeutralTemplate("file.ntpl", schema_obj={"data": {}})
     ///
+    /// # Cached, auto-reloading template for a web worker
+    /// template = NeutralTemplate("file.ntpl", cache=True, auto_reload=True)
+    ///
     /// # Empty template (set source later)
     /// template = NeutralTemplate()
     /// template.set_source("{:;data.title:}")
     /// ```
     #[new]
-    #[pyo3(signature = (path=None, schema_str=None, schema_msgpack=None, schema_obj=None))]
-    #[pyo3(text_signature = "(path=None, schema_str=None, schema_msgpack=None, schema_obj=None)")]
+    #[pyo3(signature = (path=None, schema_str=None, schema_msgpack=None, schema_obj=None, schema_yaml=None, schema_file=None, resolve_refs=true, cache=false, cache_size=128, auto_reload=false, validate_with=None, search_paths=None))]
+    #[pyo3(text_signature = "(path=None, schema_str=None, schema_msgpack=None, schema_obj=None, schema_yaml=None, schema_file=None, resolve_refs=True, cache=False, cache_size=128, auto_reload=False, validate_with=None, search_paths=None)")]
     fn new(
+        py: Python<'_>,
         path: Option<&str>,
         schema_str: Option<&str>,
         schema_msgpack: Option<&[u8]>,
         schema_obj: Option<&Bound<'_, PyAny>>,
+        schema_yaml: Option<&str>,
+        schema_file: Option<&str>,
+        resolve_refs: bool,
+        cache: bool,
+        cache_size: usize,
+        auto_reload: bool,
+        validate_with: Option<&str>,
+        search_paths: Option<Vec<String>>,
     ) -> PyResult<Self> {
         let has_str = schema_str.map_or(false, |s| !s.is_empty());
         let has_msgpack = schema_msgpack.map_or(false, |b| !b.is_empty());
         let has_obj = schema_obj.is_some();
+        let has_yaml = schema_yaml.map_or(false, |s| !s.is_empty());
+        let has_file = schema_file.map_or(false, |s| !s.is_empty());
 
-        if (has_str as u8 + has_msgpack as u8 + has_obj as u8) > 1 {
+        if (has_str as u8 + has_msgpack as u8 + has_obj as u8 + has_yaml as u8 + has_file as u8) > 1 {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "use only one schema input: schema_str, schema_msgpack, or schema_obj",
+                "use only one schema input: schema_str, schema_msgpack, schema_obj, schema_yaml, or schema_file",
             ));
         }
 
@@ -335,10 +823,38 @@ impl NeutralTemplate {
             BaseSchema::Msgpack(schema_msgpack.unwrap().to_vec())
         } else if let Some(obj) = schema_obj {
             BaseSchema::Json(Self::py_to_json(obj)?)
+        } else if has_yaml {
+            let schema: Value = serde_yaml::from_str(schema_yaml.unwrap()).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "schema is not a valid YAML string: {}",
+                    e
+                ))
+            })?;
+            BaseSchema::Json(schema)
+        } else if has_file {
+            let mut stack = Vec::new();
+            let schema = load_schema_file(Path::new(schema_file.unwrap()), resolve_refs, &mut stack)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            BaseSchema::Json(schema)
         } else {
             BaseSchema::None
         };
 
+        let validate_with = match validate_with {
+            Some(s) => Some(serde_json::from_str(s).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "validate_with is not a valid JSON string: {}",
+                    e
+                ))
+            })?),
+            None => None,
+        };
+
+        let search_paths = search_paths.unwrap_or_default();
+        if !search_paths.is_empty() {
+            warn_search_paths_include_limitation(py)?;
+        }
+
         Ok(NeutralTemplate {
             tpl,
             base_schema,
@@ -347,6 +863,11 @@ impl NeutralTemplate {
             status_text: String::new(),
             status_param: String::new(),
             has_error: false,
+            cache,
+            cache_size,
+            auto_reload,
+            validate_with,
+            search_paths,
         })
     }
 
@@ -430,6 +951,57 @@ impl NeutralTemplate {
         self.run_render(py, true)
     }
 
+    /// Renders the template and returns an iterator yielding the output in
+    /// bounded-size chunks, analogous to Jinja2's `Template.stream()`.
+    ///
+    /// The current engine only produces a complete `String`, so this renders
+    /// into a buffer inside the same detached section `render()` uses and then
+    /// slices it into `chunk_size`-sized pieces along UTF-8 character
+    /// boundaries. `get_status_code()`/`has_error()` reflect this render as
+    /// soon as `render_stream()` returns, the same as after `render()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - Number of bytes per yielded chunk. Defaults to 8192.
+    ///   A chunk that would end inside a multibyte character is extended to
+    ///   the next character boundary, so it can be up to three bytes longer.
+    ///
+    /// # Returns
+    ///
+    /// A `RenderStream` iterator of `str` chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyErr` if template loading or rendering fails.
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// template = NeutralTemplate("file.ntpl", schema_obj={"data": {"title": "Hello"}})
+    /// for chunk in template.render_stream(chunk_size=4096):
+    ///     response.write(chunk)
+    /// ```
+    #[pyo3(signature = (chunk_size=8192))]
+    #[pyo3(text_signature = "(chunk_size=8192)")]
+    fn render_stream(&mut self, py: Python<'_>, chunk_size: usize) -> PyResult<RenderStream> {
+        let contents = self.run_render(py, false)?;
+        let chunk_size = chunk_size.max(1);
+
+        let mut chunks = Vec::new();
+        let mut rest = contents.as_str();
+        while !rest.is_empty() {
+            let mut boundary = chunk_size.min(rest.len());
+            while boundary < rest.len() && !rest.is_char_boundary(boundary) {
+                boundary += 1;
+            }
+            let (chunk, remainder) = rest.split_at(boundary);
+            chunks.push(chunk.to_string());
+            rest = remainder;
+        }
+
+        Ok(RenderStream { chunks, index: 0 })
+    }
+
     /// Returns the HTTP status code from the last render.
     ///
     /// Common values include "200" for success, "404" for not found,
@@ -514,6 +1086,34 @@ impl NeutralTemplate {
         self.tpl = TplType::RawSource(source);
     }
 
+    /// Sets the list of directories searched to resolve the main template file.
+    ///
+    /// Modeled on Jinja2's `FileSystemLoader`: directories are searched in
+    /// order and the first one containing the requested template name wins,
+    /// letting a project directory shadow a shared base-template directory.
+    /// This applies only to the main template file; template includes inside
+    /// it still resolve through the underlying engine's own path logic rather
+    /// than being searched across these directories.
+    ///
+    /// # Arguments
+    ///
+    /// * `search_paths` - Directories to search, in order.
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// template = NeutralTemplate("header.ntpl")
+    /// template.set_search_paths(["themes/acme", "themes/base"])
+    /// output = template.render()
+    /// ```
+    fn set_search_paths(&mut self, py: Python<'_>, search_paths: Vec<String>) -> PyResult<()> {
+        if !search_paths.is_empty() {
+            warn_search_paths_include_limitation(py)?;
+        }
+        self.search_paths = search_paths;
+        Ok(())
+    }
+
     /// Merges a JSON schema string into the existing schema.
     ///
     /// The schema is merged recursively with any existing schema data.
@@ -548,6 +1148,40 @@ impl NeutralTemplate {
         Ok(())
     }
 
+    /// Merges a YAML schema string into the existing schema.
+    ///
+    /// The schema is merged recursively with any existing schema data.
+    ///
+    /// # Arguments
+    ///
+    /// * `yaml_str` - A valid YAML string representing the schema to merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyErr` if the YAML string is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// template = NeutralTemplate()
+    /// template.merge_schema_yaml("data:\n  title: Hello\n")
+    /// ```
+    #[pyo3(text_signature = "(yaml_str)")]
+    fn merge_schema_yaml(&mut self, yaml_str: &str) -> PyResult<()> {
+        let schema: Value = serde_yaml::from_str(yaml_str).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "schema is not a valid YAML string: {}",
+                e
+            ))
+        })?;
+        match &mut self.base_schema {
+            BaseSchema::None => self.base_schema = BaseSchema::Json(schema),
+            BaseSchema::Json(base_schema) => utils::merge_schema(base_schema, &schema),
+            BaseSchema::Msgpack(_) => self.schema_merges.push(SchemaMerge::Json(schema)),
+        }
+        Ok(())
+    }
+
     /// Merges a MessagePack schema into the existing schema.
     ///
     /// The schema is merged recursively with any existing schema data.
@@ -612,6 +1246,141 @@ impl NeutralTemplate {
         }
         Ok(())
     }
+
+    /// Loads a schema from a YAML or JSON file and merges it into the existing
+    /// schema, optionally inlining `$ref` keys that point at sibling files.
+    ///
+    /// Matrix-style split schemas compose a large schema from smaller fragment
+    /// files; an object containing `"$ref": "fragment.yaml"` is replaced by the
+    /// parsed contents of that file, resolved relative to the directory of the
+    /// file containing the reference, with sibling keys on the same object
+    /// taking precedence over the referenced file's keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the schema file to load.
+    /// * `resolve_refs` - If `true` (the default), `$ref` keys are resolved
+    ///   recursively; if `false`, they are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyErr` if:
+    /// - The file (or any file it references) cannot be read or parsed
+    /// - A `$ref` chain forms a cycle
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// template = NeutralTemplate()
+    /// template.merge_schema_file("schemas/api.yaml")
+    /// ```
+    #[pyo3(signature = (path, resolve_refs=true))]
+    #[pyo3(text_signature = "(path, resolve_refs=True)")]
+    fn merge_schema_file(&mut self, path: &str, resolve_refs: bool) -> PyResult<()> {
+        let mut stack = Vec::new();
+        let schema = load_schema_file(Path::new(path), resolve_refs, &mut stack)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+        match &mut self.base_schema {
+            BaseSchema::None => self.base_schema = BaseSchema::Json(schema),
+            BaseSchema::Json(base_schema) => utils::merge_schema(base_schema, &schema),
+            BaseSchema::Msgpack(_) => self.schema_merges.push(SchemaMerge::Json(schema)),
+        }
+        Ok(())
+    }
+
+    /// Validates the current merged schema data against a JSON Schema.
+    ///
+    /// Implements a focused Draft-7 subset: `type`, `required`, `properties`,
+    /// `additionalProperties`, `items`, `enum`, `minimum`/`maximum`,
+    /// `minLength`/`maxLength`, and `pattern`.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_json` - A JSON Schema string. If omitted, the schema passed as
+    ///   `validate_with` at construction is used instead.
+    ///
+    /// # Returns
+    ///
+    /// A Python list of `{"path": ..., "message": ...}` dicts, one per
+    /// violation found; an empty list means the data satisfies the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyErr` if:
+    /// - Neither `schema_json` nor `validate_with` provides a schema
+    /// - `schema_json` is not valid JSON
+    /// - The current schema data is MessagePack and cannot be introspected
+    ///
+    /// # Example
+    ///
+    /// ```python
+    /// template = NeutralTemplate("file.ntpl", schema_obj={"data": {"title": "Hi"}})
+    /// errors = template.validate('{"type": "object", "required": ["data"]}')
+    /// if errors:
+    ///     raise ValueError(errors)
+    /// ```
+    #[pyo3(signature = (schema_json=None))]
+    #[pyo3(text_signature = "(schema_json=None)")]
+    fn validate(&self, py: Python<'_>, schema_json: Option<&str>) -> PyResult<Py<PyList>> {
+        let schema: Value = match schema_json {
+            Some(s) => serde_json::from_str(s).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "schema_json is not a valid JSON string: {}",
+                    e
+                ))
+            })?,
+            None => self.validate_with.clone().ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "no JSON Schema provided: pass schema_json or set validate_with at construction",
+                )
+            })?,
+        };
+
+        let instance = self
+            .merged_schema_snapshot()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e))?;
+
+        let mut errors = Vec::new();
+        validate_schema(&instance, &schema, "", &mut errors);
+
+        let list = PyList::empty(py);
+        for (path, message) in errors {
+            let dict = PyDict::new(py);
+            dict.set_item("path", path)?;
+            dict.set_item("message", message)?;
+            list.append(dict)?;
+        }
+        Ok(list.unbind())
+    }
+}
+
+/// Python iterator yielding a rendered template's output in bounded-size chunks.
+///
+/// Returned by `NeutralTemplate::render_stream()`. The current implementation
+/// renders the whole template up front and slices the result, but the
+/// iterator interface is the same one a later true-incremental engine backend
+/// would fill in, so callers don't need to change.
+#[pyclass(module = "neutraltemplate")]
+struct RenderStream {
+    /// Pre-split output chunks, in order.
+    chunks: Vec<String>,
+    /// Index of the next chunk to yield.
+    index: usize,
+}
+
+#[pymethods]
+impl RenderStream {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<String> {
+        let chunk = slf.chunks.get(slf.index).cloned();
+        if chunk.is_some() {
+            slf.index += 1;
+        }
+        chunk
+    }
 }
 
 /// Python module for the Neutral template engine.
@@ -620,5 +1389,6 @@ impl NeutralTemplate {
 #[pymodule]
 fn neutraltemplate(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<NeutralTemplate>()?;
+    m.add_class::<RenderStream>()?;
     Ok(())
 }